@@ -1,38 +1,285 @@
 use crate::core::{ColourModel, Image};
 use crate::processing::Error;
 use ndarray::prelude::*;
-use ndarray::{s, Zip};
-use num_traits::{Num, NumAssignOps};
+use ndarray::{s, LinalgScalar, Zip};
+#[cfg(feature = "rayon")]
+use ndarray::parallel::prelude::*;
+use num_traits::{Num, NumAssignOps, NumCast};
+use std::iter::FromIterator;
 use std::marker::PhantomData;
 use std::marker::Sized;
 
+/// Controls how the input is virtually extended at the border so that a
+/// convolution can produce an output the same size as the input
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PaddingMode {
+    /// Do not pad, the output shrinks by the kernel radius on every side
+    None,
+    /// Pad with zeroes
+    Zero,
+    /// Pad by clamping to the nearest edge pixel
+    Replicate,
+    /// Pad by mirroring the input without repeating the edge pixel
+    Reflect,
+    /// Pad by wrapping around to the opposite edge
+    Wrap,
+}
+
 /// Perform image convolutions
 pub trait ConvolutionExt
 where
     Self: Sized,
 {
-    /// Underlying data type to perform the colution on 
+    /// Underlying data type to perform the colution on
     type Data;
 
     /// Perform a convolution returning the resultant data
     fn conv2d(&self, kernel: ArrayView3<Self::Data>) -> Result<Self, Error>;
     /// Performs the convolution inplace mutating the containers data
     fn conv2d_inplace(&mut self, kernel: ArrayView3<Self::Data>) -> Result<(), Error>;
+    /// Perform a convolution, virtually extending the input at the border
+    /// according to `padding` so the result has the same spatial size as
+    /// `self`. `PaddingMode::None` reproduces the cropping behaviour of
+    /// `conv2d`.
+    fn conv2d_with_padding(
+        &self,
+        kernel: ArrayView3<Self::Data>,
+        padding: PaddingMode,
+    ) -> Result<Self, Error>;
+    /// Perform a convolution, only evaluating windows whose top-left corner
+    /// lands on a `stride` multiple. Useful for downsampling (e.g. a stride
+    /// of 2 halves the output resolution).
+    fn conv2d_strided(
+        &self,
+        kernel: ArrayView3<Self::Data>,
+        stride: (usize, usize),
+    ) -> Result<Self, Error>;
+    /// Perform a grouped convolution. `kernel` has shape `[out_channels,
+    /// k_h, k_w, in_channels / groups]`; input and output channels are
+    /// partitioned into `groups` contiguous blocks, each convolved
+    /// independently. `groups == in_channels` is a depthwise convolution.
+    fn conv2d_grouped(
+        &self,
+        kernel: ArrayView4<Self::Data>,
+        groups: usize,
+    ) -> Result<Self, Error>;
+    /// Parallel version of [`conv2d`](Self::conv2d) that spreads the output
+    /// rows across a rayon thread pool instead of computing them serially.
+    /// Results are identical to the serial path. Requires the `rayon`
+    /// feature.
+    #[cfg(feature = "rayon")]
+    fn conv2d_parallel(&self, kernel: ArrayView3<Self::Data>) -> Result<Self, Error>
+    where
+        Self::Data: Send + Sync;
+    /// Convolves with a separable kernel as a vertical pass followed by a
+    /// horizontal pass, applying both to every channel. This is `O(2k)` work
+    /// per pixel instead of `O(k^2)` for a dense `k*k` kernel.
+    fn conv2d_separable(
+        &self,
+        col: ArrayView1<Self::Data>,
+        row: ArrayView1<Self::Data>,
+    ) -> Result<Self, Error>;
+}
+
+fn centre_offset(len: usize) -> usize {
+    len / 2 - ((len % 2 == 0) as usize)
 }
 
 fn kernel_centre(rows: usize, cols: usize) -> (usize, usize) {
-    let row_offset = rows / 2 - ((rows % 2 == 0) as usize);
-    let col_offset = cols / 2 - ((cols % 2 == 0) as usize);
-    (row_offset, col_offset) 
+    (centre_offset(rows), centre_offset(cols))
+}
+
+/// Attempts to decompose a single-channel kernel into a vertical and a
+/// horizontal 1-D kernel whose outer product reproduces it, i.e. checks that
+/// `kernel` is rank-1 by requiring every 2x2 minor to vanish. Returns `None`
+/// if the kernel is not separable.
+pub fn try_separable<T>(kernel: ArrayView2<T>) -> Option<(Array1<T>, Array1<T>)>
+where
+    T: Copy + Clone + Num + NumCast + PartialOrd,
+{
+    let (rows, cols) = kernel.dim();
+    if rows == 0 || cols == 0 {
+        return None;
+    }
+
+    // A relative tolerance so that real (virtually always floating point)
+    // separable kernels like a sampled Gaussian aren't rejected by rounding
+    // noise in the minor/reconstruction checks below. For integer `T` this
+    // casts to zero, so those checks stay exact.
+    let epsilon = <T as NumCast>::from(1e-6_f64).unwrap_or_else(T::zero);
+    let approx_eq = |a: T, b: T| {
+        let diff = if a > b { a - b } else { b - a };
+        let abs_a = if a < T::zero() { T::zero() - a } else { a };
+        let abs_b = if b < T::zero() { T::zero() - b } else { b };
+        let scale = if abs_a > abs_b { abs_a } else { abs_b };
+        let tolerance = epsilon * (if scale > T::one() { scale } else { T::one() });
+        diff <= tolerance
+    };
+
+    for i in 0..rows {
+        for p in (i + 1)..rows {
+            for j in 0..cols {
+                for q in (j + 1)..cols {
+                    let minor = kernel[[i, j]] * kernel[[p, q]] - kernel[[i, q]] * kernel[[p, j]];
+                    if !approx_eq(minor, T::zero()) {
+                        return None;
+                    }
+                }
+            }
+        }
+    }
+
+    // Factor through the largest-magnitude entry so the recovered row is a
+    // division by a value as far from zero as the kernel allows
+    let (mut pivot_i, mut pivot_j, mut pivot_abs) = (0, 0, T::zero());
+    for i in 0..rows {
+        for j in 0..cols {
+            let value = kernel[[i, j]];
+            let abs = if value < T::zero() { T::zero() - value } else { value };
+            if abs > pivot_abs {
+                pivot_i = i;
+                pivot_j = j;
+                pivot_abs = abs;
+            }
+        }
+    }
+
+    if pivot_abs == T::zero() {
+        return Some((Array1::zeros(rows), Array1::zeros(cols)));
+    }
+
+    let pivot = kernel[[pivot_i, pivot_j]];
+    let col = Array1::from_iter((0..rows).map(|i| kernel[[i, pivot_j]]));
+    let row = Array1::from_iter((0..cols).map(|j| kernel[[pivot_i, j]] / pivot));
+
+    // For integer `T`, the division above truncates, so `col` and `row` can
+    // come out consistent with the pivot row/column alone while failing to
+    // reproduce the rest of the kernel. Verify the outer product matches
+    // (within the same tolerance as the minor check above) before trusting
+    // the decomposition, falling back to the dense path otherwise.
+    for i in 0..rows {
+        for j in 0..cols {
+            if !approx_eq(col[i] * row[j], kernel[[i, j]]) {
+                return None;
+            }
+        }
+    }
+
+    Some((col, row))
+}
+
+/// Maps a (possibly out of bounds) index along one axis back into `[0,
+/// len)` according to `mode`, or returns `None` if the position should be
+/// filled with zero
+fn pad_index(i: isize, len: usize, mode: PaddingMode) -> Option<usize> {
+    let len_i = len as isize;
+    if i >= 0 && i < len_i {
+        return Some(i as usize);
+    }
+    match mode {
+        PaddingMode::None | PaddingMode::Zero => None,
+        PaddingMode::Replicate => Some(i.max(0).min(len_i - 1) as usize),
+        PaddingMode::Reflect => {
+            // A length-1 axis has no neighbour to reflect onto, and the
+            // general bounce below reflects about `0` and `len - 1`, which
+            // coincide for len == 1, so it would loop forever (oscillating
+            // between indices 1 and -1).
+            if len <= 1 {
+                return Some(0);
+            }
+            let mut idx = i;
+            while idx < 0 || idx >= len_i {
+                idx = if idx < 0 { -idx } else { 2 * (len_i - 1) - idx };
+            }
+            Some(idx as usize)
+        }
+        PaddingMode::Wrap => Some(idx_rem_euclid(i, len_i) as usize),
+    }
+}
+
+fn idx_rem_euclid(i: isize, len: isize) -> isize {
+    let r = i % len;
+    if r < 0 {
+        r + len
+    } else {
+        r
+    }
+}
+
+/// Builds a copy of `input` virtually extended by `row_offset`/`col_offset`
+/// on every side according to `mode`
+fn pad_array<T>(input: &Array3<T>, row_offset: usize, col_offset: usize, mode: PaddingMode) -> Array3<T>
+where
+    T: Copy + Clone + Num,
+{
+    let (rows, cols, channels) = input.dim();
+    let shape = (rows + 2 * row_offset, cols + 2 * col_offset, channels);
+    let mut padded = Array3::zeros(shape);
+
+    for i in 0..shape.0 {
+        let src_i = pad_index(i as isize - row_offset as isize, rows, mode);
+        for j in 0..shape.1 {
+            let src_j = pad_index(j as isize - col_offset as isize, cols, mode);
+            if let (Some(si), Some(sj)) = (src_i, src_j) {
+                for k in 0..channels {
+                    padded[[i, j, k]] = input[[si, sj, k]];
+                }
+            }
+        }
+    }
+
+    padded
+}
+
+/// Builds a copy of `input` virtually extended by `offset` on both ends of
+/// the length axis according to `mode`
+fn pad_array_1d<T>(input: &Array2<T>, offset: usize, mode: PaddingMode) -> Array2<T>
+where
+    T: Copy + Clone + Num,
+{
+    let (len, channels) = input.dim();
+    let shape = (len + 2 * offset, channels);
+    let mut padded = Array2::zeros(shape);
+
+    for i in 0..shape.0 {
+        if let Some(si) = pad_index(i as isize - offset as isize, len, mode) {
+            for c in 0..channels {
+                padded[[i, c]] = input[[si, c]];
+            }
+        }
+    }
+
+    padded
 }
 
 impl<T> ConvolutionExt for Array3<T>
 where
-    T: Copy + Clone + Num + NumAssignOps,
+    T: Copy + Clone + Num + NumAssignOps + PartialOrd,
 {
     type Data = T;
 
     fn conv2d(&self, kernel: ArrayView3<Self::Data>) -> Result<Self, Error> {
+        // `conv2d_separable` sizes its output by the true window count
+        // (`in - k + 1`), while this dense path sizes by the
+        // `kernel_centre` shrink; the two disagree for even-length kernels.
+        // Auto-dispatching here would make `conv2d`'s output shape depend
+        // on the kernel's values instead of just its dimensions, so the
+        // separable fast path is only reachable explicitly via
+        // `try_separable`/`conv2d_separable`.
+        self.conv2d_with_padding(kernel, PaddingMode::None)
+    }
+
+    fn conv2d_inplace(&mut self, kernel: ArrayView3<Self::Data>) -> Result<(), Error> {
+        let data = self.conv2d_with_padding(kernel, PaddingMode::Replicate)?;
+        self.assign(&data);
+        Ok(())
+    }
+
+    fn conv2d_with_padding(
+        &self,
+        kernel: ArrayView3<Self::Data>,
+        padding: PaddingMode,
+    ) -> Result<Self, Error> {
         if self.shape()[2] != kernel.shape()[2] {
             Err(Error::ChannelDimensionMismatch)
         } else {
@@ -40,17 +287,27 @@ where
             // Bit icky but handles fact that uncentred convolutions will cross the bounds
             // otherwise
             let (row_offset, col_offset) = kernel_centre(k_s[0], k_s[1]);
+
+            let source = match padding {
+                PaddingMode::None => None,
+                _ => Some(pad_array(self, row_offset, col_offset, padding)),
+            };
+            let windowed = source.as_ref().unwrap_or(self);
+
             // row_offset * 2 may not equal k_s[0] due to truncation
-            let shape = (
-                self.shape()[0] - row_offset * 2,
-                self.shape()[1] - col_offset * 2,
-                self.shape()[2],
-            );
+            let shape = match padding {
+                PaddingMode::None => (
+                    self.shape()[0] - row_offset * 2,
+                    self.shape()[1] - col_offset * 2,
+                    self.shape()[2],
+                ),
+                _ => (self.shape()[0], self.shape()[1], self.shape()[2]),
+            };
 
             if shape.0 > 0 && shape.1 > 0 {
                 let mut result = Self::zeros(shape);
 
-                Zip::indexed(self.windows(kernel.dim())).apply(|(i, j, _), window| {
+                Zip::indexed(windowed.windows(kernel.dim())).apply(|(i, j, _), window| {
                     let mult = &window * &kernel;
                     let sums = mult.sum_axis(Axis(0)).sum_axis(Axis(0));
                     result.slice_mut(s![i, j, ..]).assign(&sums);
@@ -62,26 +319,200 @@ where
         }
     }
 
-    fn conv2d_inplace(&mut self, kernel: ArrayView3<Self::Data>) -> Result<(), Error> {
-        let data = self.conv2d(kernel)?;
-        let shape = kernel.shape();
-        let centre = kernel_centre(shape[0], shape[1]);
-        for (d, v) in self.indexed_iter_mut() {
-            if d.0 < centre.0 || d.1 < centre.1 {
-                continue;
+    fn conv2d_strided(
+        &self,
+        kernel: ArrayView3<Self::Data>,
+        stride: (usize, usize),
+    ) -> Result<Self, Error> {
+        if self.shape()[2] != kernel.shape()[2] {
+            return Err(Error::ChannelDimensionMismatch);
+        }
+        if stride.0 < 1 || stride.1 < 1 {
+            return Err(Error::InvalidDimensions);
+        }
+
+        let k_s = kernel.shape();
+        let in_rows = self.shape()[0];
+        let in_cols = self.shape()[1];
+
+        if k_s[0] > in_rows || k_s[1] > in_cols {
+            return Err(Error::InvalidDimensions);
+        }
+
+        let out_rows = (in_rows - k_s[0]) / stride.0 + 1;
+        let out_cols = (in_cols - k_s[1]) / stride.1 + 1;
+        let shape = (out_rows, out_cols, self.shape()[2]);
+        let mut result = Self::zeros(shape);
+
+        for (oi, i) in (0..in_rows).step_by(stride.0).take(out_rows).enumerate() {
+            for (oj, j) in (0..in_cols).step_by(stride.1).take(out_cols).enumerate() {
+                let window = self.slice(s![i..i + k_s[0], j..j + k_s[1], ..]);
+                let mult = &window * &kernel;
+                let sums = mult.sum_axis(Axis(0)).sum_axis(Axis(0));
+                result.slice_mut(s![oi, oj, ..]).assign(&sums);
             }
-            let centred = (d.0 - centre.0, d.1 - centre.1, d.2);
-            if let Some(d) = data.get(centred) {
-                *v = *d;
+        }
+
+        Ok(result)
+    }
+
+    fn conv2d_grouped(
+        &self,
+        kernel: ArrayView4<Self::Data>,
+        groups: usize,
+    ) -> Result<Self, Error> {
+        let in_channels = self.shape()[2];
+        let out_channels = kernel.shape()[0];
+        let k_s = (kernel.shape()[1], kernel.shape()[2], kernel.shape()[3]);
+
+        if groups == 0
+            || in_channels % groups != 0
+            || out_channels % groups != 0
+            || k_s.2 != in_channels / groups
+        {
+            return Err(Error::ChannelDimensionMismatch);
+        }
+
+        if k_s.0 > self.shape()[0] || k_s.1 > self.shape()[1] {
+            return Err(Error::InvalidDimensions);
+        }
+
+        let in_channels_per_group = in_channels / groups;
+        let out_channels_per_group = out_channels / groups;
+        let (row_offset, col_offset) = kernel_centre(k_s.0, k_s.1);
+        let shape = (
+            self.shape()[0] - row_offset * 2,
+            self.shape()[1] - col_offset * 2,
+            out_channels,
+        );
+
+        if !(shape.0 > 0 && shape.1 > 0) {
+            return Err(Error::InvalidDimensions);
+        }
+
+        let mut result = Self::zeros(shape);
+
+        for g in 0..groups {
+            let in_start = g * in_channels_per_group;
+            let out_start = g * out_channels_per_group;
+            let input_group = self.slice(s![.., .., in_start..in_start + in_channels_per_group]);
+
+            for oc in 0..out_channels_per_group {
+                let group_kernel = kernel.slice(s![out_start + oc, .., .., ..]);
+
+                Zip::indexed(input_group.windows(group_kernel.dim())).apply(|(i, j, _), window| {
+                    let mult = &window * &group_kernel;
+                    result[[i, j, out_start + oc]] = mult.sum();
+                });
             }
         }
-        Ok(())
+
+        Ok(result)
+    }
+
+    #[cfg(feature = "rayon")]
+    fn conv2d_parallel(&self, kernel: ArrayView3<Self::Data>) -> Result<Self, Error>
+    where
+        Self::Data: Send + Sync,
+    {
+        if self.shape()[2] != kernel.shape()[2] {
+            return Err(Error::ChannelDimensionMismatch);
+        }
+
+        let k_s = kernel.shape();
+        let (row_offset, col_offset) = kernel_centre(k_s[0], k_s[1]);
+        let out_rows = self.shape()[0] - row_offset * 2;
+        let out_cols = self.shape()[1] - col_offset * 2;
+
+        if !(out_rows > 0 && out_cols > 0) {
+            return Err(Error::InvalidDimensions);
+        }
+
+        let mut result = Self::zeros((out_rows, out_cols, self.shape()[2]));
+        // Number of full windows along each axis; as in `conv2d`, output
+        // positions past the last full window are left at zero
+        let win_rows = (self.shape()[0] + 1).saturating_sub(k_s[0]);
+        let win_cols = (self.shape()[1] + 1).saturating_sub(k_s[1]);
+
+        // Drive the parallelism off `result`'s own rows only: ndarray 0.13
+        // has no `axis_windows`, and even where it exists, zipping it
+        // against `outer_iter_mut()` mismatches a 3-D producer against a
+        // 1-D one. Each row's input window is instead sliced straight out
+        // of `self` inside the closure.
+        result
+            .outer_iter_mut()
+            .into_par_iter()
+            .enumerate()
+            .for_each(|(i, mut out_row)| {
+                if i >= win_rows {
+                    return;
+                }
+                for j in 0..win_cols {
+                    let window = self.slice(s![i..i + k_s[0], j..j + k_s[1], ..]);
+                    let mult = &window * &kernel;
+                    let sums = mult.sum_axis(Axis(0)).sum_axis(Axis(0));
+                    out_row.slice_mut(s![j, ..]).assign(&sums);
+                }
+            });
+
+        Ok(result)
+    }
+
+    fn conv2d_separable(
+        &self,
+        col: ArrayView1<Self::Data>,
+        row: ArrayView1<Self::Data>,
+    ) -> Result<Self, Error> {
+        let (k_h, k_w) = (col.len(), row.len());
+        let channels = self.shape()[2];
+        let in_rows = self.shape()[0];
+        let in_cols = self.shape()[1];
+
+        // `kernel_centre` rounds down for even-length axes, which under-
+        // shrinks the output versus the true number of valid windows; use
+        // that directly instead, as `conv2d_strided` already does.
+        if k_h > in_rows || k_w > in_cols {
+            return Err(Error::InvalidDimensions);
+        }
+
+        let mid_rows = in_rows - k_h + 1;
+        let out_cols = in_cols - k_w + 1;
+
+        // Vertical pass: convolve each column of every channel with `col`
+        let mut vertical = Self::zeros((mid_rows, in_cols, channels));
+        for i in 0..mid_rows {
+            for j in 0..in_cols {
+                for c in 0..channels {
+                    let mut sum = T::zero();
+                    for (k, &weight) in col.iter().enumerate() {
+                        sum += self[[i + k, j, c]] * weight;
+                    }
+                    vertical[[i, j, c]] = sum;
+                }
+            }
+        }
+
+        // Horizontal pass: convolve each row of the intermediate with `row`
+        let mut result = Self::zeros((mid_rows, out_cols, channels));
+        for i in 0..mid_rows {
+            for j in 0..out_cols {
+                for c in 0..channels {
+                    let mut sum = T::zero();
+                    for (k, &weight) in row.iter().enumerate() {
+                        sum += vertical[[i, j + k, c]] * weight;
+                    }
+                    result[[i, j, c]] = sum;
+                }
+            }
+        }
+
+        Ok(result)
     }
 }
 
 impl<T, C> ConvolutionExt for Image<T, C>
 where
-    T: Copy + Clone + Num + NumAssignOps,
+    T: Copy + Clone + Num + NumAssignOps + PartialOrd,
     C: ColourModel,
 {
     type Data = T;
@@ -96,13 +527,264 @@ where
     fn conv2d_inplace(&mut self, kernel: ArrayView3<Self::Data>) -> Result<(), Error> {
         self.data.conv2d_inplace(kernel)
     }
+
+    fn conv2d_with_padding(
+        &self,
+        kernel: ArrayView3<Self::Data>,
+        padding: PaddingMode,
+    ) -> Result<Self, Error> {
+        let data = self.data.conv2d_with_padding(kernel, padding)?;
+        Ok(Self {
+            data,
+            model: PhantomData,
+        })
+    }
+
+    fn conv2d_strided(
+        &self,
+        kernel: ArrayView3<Self::Data>,
+        stride: (usize, usize),
+    ) -> Result<Self, Error> {
+        let data = self.data.conv2d_strided(kernel, stride)?;
+        Ok(Self {
+            data,
+            model: PhantomData,
+        })
+    }
+
+    fn conv2d_grouped(
+        &self,
+        kernel: ArrayView4<Self::Data>,
+        groups: usize,
+    ) -> Result<Self, Error> {
+        let data = self.data.conv2d_grouped(kernel, groups)?;
+        Ok(Self {
+            data,
+            model: PhantomData,
+        })
+    }
+
+    #[cfg(feature = "rayon")]
+    fn conv2d_parallel(&self, kernel: ArrayView3<Self::Data>) -> Result<Self, Error>
+    where
+        Self::Data: Send + Sync,
+    {
+        let data = self.data.conv2d_parallel(kernel)?;
+        Ok(Self {
+            data,
+            model: PhantomData,
+        })
+    }
+
+    fn conv2d_separable(
+        &self,
+        col: ArrayView1<Self::Data>,
+        row: ArrayView1<Self::Data>,
+    ) -> Result<Self, Error> {
+        let data = self.data.conv2d_separable(col, row)?;
+        Ok(Self {
+            data,
+            model: PhantomData,
+        })
+    }
+}
+
+/// Convolution lowered to a single matrix multiply (im2col + GEMM), which
+/// avoids allocating and reducing a temporary for every output pixel and is
+/// faster than `ConvolutionExt::conv2d` for large kernels or channel counts
+pub trait Im2colConvExt
+where
+    Self: Sized,
+{
+    /// Underlying data type to perform the convolution on
+    type Data;
+
+    /// Perform a convolution via im2col + GEMM; numerically identical to
+    /// `ConvolutionExt::conv2d`
+    fn conv2d_im2col(&self, kernel: ArrayView3<Self::Data>) -> Result<Self, Error>;
+}
+
+impl<T> Im2colConvExt for Array3<T>
+where
+    T: Copy + Clone + Num + NumAssignOps + LinalgScalar,
+{
+    type Data = T;
+
+    fn conv2d_im2col(&self, kernel: ArrayView3<Self::Data>) -> Result<Self, Error> {
+        if self.shape()[2] != kernel.shape()[2] {
+            return Err(Error::ChannelDimensionMismatch);
+        }
+
+        let k_s = kernel.shape();
+        let (k_h, k_w, channels) = (k_s[0], k_s[1], k_s[2]);
+        let (row_offset, col_offset) = kernel_centre(k_h, k_w);
+        let out_h = self.shape()[0] - row_offset * 2;
+        let out_w = self.shape()[1] - col_offset * 2;
+
+        if !(out_h > 0 && out_w > 0) {
+            return Err(Error::InvalidDimensions);
+        }
+
+        let patch_len = k_h * k_w * channels;
+
+        // Unfold every kernel-sized window into one row of `columns`, walking
+        // (i, j, c) in the same order as the kernel is flattened below
+        let mut columns = Array2::<T>::zeros((out_h * out_w, patch_len));
+        Zip::indexed(self.windows((k_h, k_w, channels))).apply(|(i, j, _), window| {
+            for (col, value) in window.iter().enumerate() {
+                columns[[i * out_w + j, col]] = *value;
+            }
+        });
+
+        // `conv2d` never mixes channels, so the reshaped kernel is
+        // block-diagonal: output channel c only draws from input channel c
+        let mut weights = Array2::<T>::zeros((patch_len, channels));
+        for i in 0..k_h {
+            for j in 0..k_w {
+                for c in 0..channels {
+                    weights[[(i * k_w + j) * channels + c, c]] = kernel[[i, j, c]];
+                }
+            }
+        }
+
+        let flat = columns.dot(&weights);
+        let result = flat
+            .into_shape((out_h, out_w, channels))
+            .expect("im2col output has the right number of elements");
+
+        Ok(result)
+    }
+}
+
+impl<T, C> Im2colConvExt for Image<T, C>
+where
+    T: Copy + Clone + Num + NumAssignOps + LinalgScalar,
+    C: ColourModel,
+{
+    type Data = T;
+
+    fn conv2d_im2col(&self, kernel: ArrayView3<Self::Data>) -> Result<Self, Error> {
+        let data = self.data.conv2d_im2col(kernel)?;
+        Ok(Self {
+            data,
+            model: PhantomData,
+        })
+    }
+}
+
+/// Perform 1-D convolutions, for signal data such as audio or per-pixel
+/// spectra (`Array2` shaped `[length, channels]`)
+pub trait Convolution1DExt
+where
+    Self: Sized,
+{
+    /// Underlying data type to perform the convolution on
+    type Data;
+
+    /// Perform a convolution returning the resultant data
+    fn conv1d(&self, kernel: ArrayView2<Self::Data>) -> Result<Self, Error>;
+    /// Perform a convolution, virtually extending the input at the border
+    /// according to `padding` so the result has the same length as `self`.
+    /// `PaddingMode::None` reproduces the cropping behaviour of `conv1d`.
+    fn conv1d_with_padding(
+        &self,
+        kernel: ArrayView2<Self::Data>,
+        padding: PaddingMode,
+    ) -> Result<Self, Error>;
+    /// Perform a convolution, only evaluating windows whose start lands on a
+    /// `stride` multiple
+    fn conv1d_strided(
+        &self,
+        kernel: ArrayView2<Self::Data>,
+        stride: usize,
+    ) -> Result<Self, Error>;
+}
+
+impl<T> Convolution1DExt for Array2<T>
+where
+    T: Copy + Clone + Num + NumAssignOps,
+{
+    type Data = T;
+
+    fn conv1d(&self, kernel: ArrayView2<Self::Data>) -> Result<Self, Error> {
+        self.conv1d_with_padding(kernel, PaddingMode::None)
+    }
+
+    fn conv1d_with_padding(
+        &self,
+        kernel: ArrayView2<Self::Data>,
+        padding: PaddingMode,
+    ) -> Result<Self, Error> {
+        if self.shape()[1] != kernel.shape()[1] {
+            return Err(Error::ChannelDimensionMismatch);
+        }
+
+        let offset = centre_offset(kernel.shape()[0]);
+
+        let source = match padding {
+            PaddingMode::None => None,
+            _ => Some(pad_array_1d(self, offset, padding)),
+        };
+        let windowed = source.as_ref().unwrap_or(self);
+
+        let len = match padding {
+            PaddingMode::None => self.shape()[0] - offset * 2,
+            _ => self.shape()[0],
+        };
+
+        if len == 0 {
+            return Err(Error::InvalidDimensions);
+        }
+
+        let mut result = Self::zeros((len, self.shape()[1]));
+
+        Zip::indexed(windowed.windows(kernel.dim())).apply(|(i, _), window| {
+            let mult = &window * &kernel;
+            let sums = mult.sum_axis(Axis(0));
+            result.slice_mut(s![i, ..]).assign(&sums);
+        });
+
+        Ok(result)
+    }
+
+    fn conv1d_strided(
+        &self,
+        kernel: ArrayView2<Self::Data>,
+        stride: usize,
+    ) -> Result<Self, Error> {
+        if self.shape()[1] != kernel.shape()[1] {
+            return Err(Error::ChannelDimensionMismatch);
+        }
+        if stride < 1 {
+            return Err(Error::InvalidDimensions);
+        }
+
+        let k_len = kernel.shape()[0];
+        let in_len = self.shape()[0];
+
+        if k_len > in_len {
+            return Err(Error::InvalidDimensions);
+        }
+
+        let out_len = (in_len - k_len) / stride + 1;
+        let mut result = Self::zeros((out_len, self.shape()[1]));
+
+        for (oi, i) in (0..in_len).step_by(stride).take(out_len).enumerate() {
+            let window = self.slice(s![i..i + k_len, ..]);
+            let mult = &window * &kernel;
+            let sums = mult.sum_axis(Axis(0));
+            result.slice_mut(s![oi, ..]).assign(&sums);
+        }
+
+        Ok(result)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::core::colour_models::{Gray, RGB};
-    use ndarray::arr3;
+    use ndarray::{arr2, arr3};
 
     #[test]
     fn bad_dimensions() {
@@ -152,11 +834,11 @@ mod tests {
                                 0, 0, 1, 1, 0,
                                 0, 1, 1, 0, 0];
 
-        let output_pixels = vec![1, 1, 1, 0, 0,
-                                 0, 4, 3, 4, 0,
-                                 0, 2, 4, 3, 1,
-                                 0, 2, 3, 4, 0,
-                                 0, 1, 1, 0, 0];
+        let output_pixels = vec![4, 4, 4, 2, 1,
+                                 2, 4, 3, 4, 2,
+                                 1, 2, 4, 3, 3,
+                                 1, 2, 3, 4, 2,
+                                 1, 3, 3, 2, 1];
 
         let kern = arr3(&[[[1], [0], [1]],
                           [[0], [1], [0]],
@@ -169,4 +851,305 @@ mod tests {
 
         assert_eq!(expected, input);
     }
+
+    #[test]
+    fn conv_with_zero_padding() {
+        let input_pixels = vec![1, 1, 1, 0, 0,
+                                0, 1, 1, 1, 0,
+                                0, 0, 1, 1, 1,
+                                0, 0, 1, 1, 0,
+                                0, 1, 1, 0, 0];
+
+        let output_pixels = vec![2, 2, 3, 1, 1,
+                                 1, 4, 3, 4, 1,
+                                 1, 2, 4, 3, 3,
+                                 1, 2, 3, 4, 1,
+                                 0, 2, 2, 1, 1];
+
+        let kern = arr3(&[[[1], [0], [1]],
+                          [[0], [1], [0]],
+                          [[1], [0], [1]]]);
+
+        let input = Image::<u8, Gray>::from_shape_data(5, 5, input_pixels);
+        let expected = Image::<u8, Gray>::from_shape_data(5, 5, output_pixels);
+
+        assert_eq!(
+            Ok(expected),
+            input.conv2d_with_padding(kern.view(), PaddingMode::Zero)
+        );
+    }
+
+    #[test]
+    fn conv_with_replicate_padding_matches_inplace() {
+        let input_pixels = vec![1, 1, 1, 0, 0,
+                                0, 1, 1, 1, 0,
+                                0, 0, 1, 1, 1,
+                                0, 0, 1, 1, 0,
+                                0, 1, 1, 0, 0];
+
+        let kern = arr3(&[[[1], [0], [1]],
+                          [[0], [1], [0]],
+                          [[1], [0], [1]]]);
+
+        let mut inplace = Image::<u8, Gray>::from_shape_data(5, 5, input_pixels.clone());
+        inplace.conv2d_inplace(kern.view()).unwrap();
+
+        let input = Image::<u8, Gray>::from_shape_data(5, 5, input_pixels);
+        let padded = input.conv2d_with_padding(kern.view(), PaddingMode::Replicate);
+
+        assert_eq!(Ok(inplace), padded);
+    }
+
+    #[test]
+    fn none_padding_matches_conv2d() {
+        let input_pixels = vec![1, 1, 1, 0, 0,
+                                0, 1, 1, 1, 0,
+                                0, 0, 1, 1, 1,
+                                0, 0, 1, 1, 0,
+                                0, 1, 1, 0, 0];
+
+        let kern = arr3(&[[[1], [0], [1]],
+                          [[0], [1], [0]],
+                          [[1], [0], [1]]]);
+
+        let input = Image::<u8, Gray>::from_shape_data(5, 5, input_pixels);
+
+        assert_eq!(
+            input.conv2d(kern.view()),
+            input.conv2d_with_padding(kern.view(), PaddingMode::None)
+        );
+    }
+
+    #[test]
+    fn strided_conv() {
+        let input_pixels = vec![1, 1, 1, 0, 0,
+                                0, 1, 1, 1, 0,
+                                0, 0, 1, 1, 1,
+                                0, 0, 1, 1, 0,
+                                0, 1, 1, 0, 0];
+        let output_pixels = vec![4, 4,
+                                 2, 4];
+
+        let kern = arr3(&[[[1], [0], [1]],
+                          [[0], [1], [0]],
+                          [[1], [0], [1]]]);
+
+        let input = Image::<u8, Gray>::from_shape_data(5, 5, input_pixels);
+        let expected = Image::<u8, Gray>::from_shape_data(2, 2, output_pixels);
+
+        assert_eq!(Ok(expected), input.conv2d_strided(kern.view(), (2, 2)));
+    }
+
+    #[test]
+    fn strided_conv_rejects_zero_stride() {
+        let i = Image::<f64, RGB>::new(5, 5);
+        let kern = Array3::<f64>::zeros((2, 2, RGB::channels()));
+
+        assert_eq!(
+            Err(Error::InvalidDimensions),
+            i.conv2d_strided(kern.view(), (0, 1))
+        );
+    }
+
+    #[test]
+    fn depthwise_conv() {
+        let input_pixels = vec![1, 1, 1, 0, 0,
+                                0, 1, 1, 1, 0,
+                                0, 0, 1, 1, 1,
+                                0, 0, 1, 1, 0,
+                                0, 1, 1, 0, 0];
+        let mut input = Array3::<u8>::zeros((5, 5, 2));
+        for (idx, &v) in input_pixels.iter().enumerate() {
+            input[[idx / 5, idx % 5, 0]] = v;
+            input[[idx / 5, idx % 5, 1]] = v;
+        }
+
+        let kern = arr3(&[[[1], [0], [1]],
+                          [[0], [1], [0]],
+                          [[1], [0], [1]]]);
+        let mut grouped_kern = Array4::<u8>::zeros((2, 3, 3, 1));
+        grouped_kern.slice_mut(s![0, .., .., ..]).assign(&kern);
+        grouped_kern.slice_mut(s![1, .., .., ..]).assign(&kern);
+
+        let output_pixels = vec![4, 3, 4,
+                                 2, 4, 3,
+                                 2, 3, 4];
+        let mut expected = Array3::<u8>::zeros((3, 3, 2));
+        for (idx, &v) in output_pixels.iter().enumerate() {
+            expected[[idx / 3, idx % 3, 0]] = v;
+            expected[[idx / 3, idx % 3, 1]] = v;
+        }
+
+        let result = input.conv2d_grouped(grouped_kern.view(), 2).unwrap();
+        assert_eq!(expected, result);
+    }
+
+    #[test]
+    fn grouped_conv_bad_dimensions() {
+        let input = Array3::<f64>::zeros((5, 5, 4));
+        let kern = Array4::<f64>::zeros((2, 3, 3, 1));
+
+        assert_eq!(
+            Err(Error::ChannelDimensionMismatch),
+            input.conv2d_grouped(kern.view(), 3)
+        );
+    }
+
+    #[test]
+    fn grouped_conv_kernel_larger_than_input() {
+        let input = Array3::<f64>::zeros((3, 3, 4));
+        let kern = Array4::<f64>::zeros((4, 5, 5, 1));
+
+        assert_eq!(
+            Err(Error::InvalidDimensions),
+            input.conv2d_grouped(kern.view(), 4)
+        );
+    }
+
+    #[test]
+    fn im2col_matches_conv2d() {
+        let input_pixels: Vec<f64> = vec![1, 1, 1, 0, 0,
+                                          0, 1, 1, 1, 0,
+                                          0, 0, 1, 1, 1,
+                                          0, 0, 1, 1, 0,
+                                          0, 1, 1, 0, 0]
+            .into_iter()
+            .map(f64::from)
+            .collect();
+
+        let kern = arr3(&[[[1.0], [0.0], [1.0]],
+                          [[0.0], [1.0], [0.0]],
+                          [[1.0], [0.0], [1.0]]]);
+
+        let input = Image::<f64, Gray>::from_shape_data(5, 5, input_pixels);
+
+        assert_eq!(
+            input.conv2d(kern.view()),
+            input.conv2d_im2col(kern.view())
+        );
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn parallel_conv_matches_serial() {
+        let input_pixels = vec![1, 1, 1, 0, 0,
+                                0, 1, 1, 1, 0,
+                                0, 0, 1, 1, 1,
+                                0, 0, 1, 1, 0,
+                                0, 1, 1, 0, 0];
+
+        let kern = arr3(&[[[1], [0], [1]],
+                          [[0], [1], [0]],
+                          [[1], [0], [1]]]);
+
+        let input = Image::<u8, Gray>::from_shape_data(5, 5, input_pixels);
+
+        assert_eq!(
+            input.conv2d(kern.view()),
+            input.conv2d_parallel(kern.view())
+        );
+    }
+
+    #[test]
+    fn try_separable_detects_rank1_kernel() {
+        let kernel: Array2<i32> = arr2(&[[1, 1, 1], [1, 1, 1], [1, 1, 1]]);
+        let (col, row) = try_separable(kernel.view()).unwrap();
+
+        assert_eq!(Array1::from_vec(vec![1, 1, 1]), col);
+        assert_eq!(Array1::from_vec(vec![1, 1, 1]), row);
+    }
+
+    #[test]
+    fn try_separable_rejects_non_separable_kernel() {
+        let kernel: Array2<i32> = arr2(&[[1, 0, 1], [0, 1, 0], [1, 0, 1]]);
+        assert_eq!(None, try_separable(kernel.view()));
+    }
+
+    #[test]
+    fn try_separable_detects_float_gaussian_kernel() {
+        // A discrete 1-D Gaussian sampled at x = -1, 0, 1; its outer product
+        // is separable in theory, but the minors computed from it are not
+        // exactly zero due to floating point rounding.
+        let edge: f64 = (-0.5_f64).exp();
+        let samples = [edge, 1.0, edge];
+        let kernel = Array2::from_shape_fn((3, 3), |(i, j)| samples[i] * samples[j]);
+
+        let (col, row) = try_separable(kernel.view()).expect("sampled Gaussian is separable");
+
+        for i in 0..3 {
+            for j in 0..3 {
+                assert!((col[i] * row[j] - kernel[[i, j]]).abs() < 1e-9);
+            }
+        }
+    }
+
+    #[test]
+    fn conv2d_separable_matches_dense() {
+        let input_pixels = vec![1, 1, 1, 0, 0,
+                                0, 1, 1, 1, 0,
+                                0, 0, 1, 1, 1,
+                                0, 0, 1, 1, 0,
+                                0, 1, 1, 0, 0];
+        let output_pixels = vec![6, 7, 6,
+                                 4, 7, 7,
+                                 4, 6, 6];
+
+        let col = Array1::from_vec(vec![1, 1, 1]);
+        let row = Array1::from_vec(vec![1, 1, 1]);
+        let dense_kern = arr3(&[[[1], [1], [1]],
+                               [[1], [1], [1]],
+                               [[1], [1], [1]]]);
+
+        let input = Image::<u8, Gray>::from_shape_data(5, 5, input_pixels);
+
+        assert_eq!(
+            Ok(Image::<u8, Gray>::from_shape_data(3, 3, output_pixels.clone())),
+            input.conv2d_separable(col.view(), row.view())
+        );
+        assert_eq!(
+            Ok(Image::<u8, Gray>::from_shape_data(3, 3, output_pixels)),
+            input.conv2d(dense_kern.view())
+        );
+    }
+
+    #[test]
+    fn conv1d_bad_dimensions() {
+        let input = Array2::<f64>::zeros((5, 2));
+        let bad_kern = Array2::<f64>::zeros((3, 1));
+
+        assert_eq!(
+            Err(Error::ChannelDimensionMismatch),
+            input.conv1d(bad_kern.view())
+        );
+    }
+
+    #[test]
+    fn basic_conv1d() {
+        let input = arr2(&[[1], [2], [3], [4], [5]]);
+        let kern = arr2(&[[1], [0], [1]]);
+        let expected = arr2(&[[4], [6], [8]]);
+
+        assert_eq!(Ok(expected), input.conv1d(kern.view()));
+    }
+
+    #[test]
+    fn conv1d_with_zero_padding() {
+        let input = arr2(&[[1], [2], [3], [4], [5]]);
+        let kern = arr2(&[[1], [0], [1]]);
+        let expected = arr2(&[[2], [4], [6], [8], [4]]);
+
+        assert_eq!(
+            Ok(expected),
+            input.conv1d_with_padding(kern.view(), PaddingMode::Zero)
+        );
+    }
+
+    #[test]
+    fn strided_conv1d() {
+        let input = arr2(&[[1], [2], [3], [4], [5]]);
+        let kern = arr2(&[[1], [0], [1]]);
+        let expected = arr2(&[[4], [8]]);
+
+        assert_eq!(Ok(expected), input.conv1d_strided(kern.view(), 2));
+    }
 }